@@ -0,0 +1,105 @@
+use anyhow::{anyhow, Result};
+use clap::Subcommand;
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    /// Fetch a Validators List from a URL or local file and verify it
+    Load {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        arg: Option<Vec<String>>,
+    },
+    /// Build and sign a new Validators List
+    Sign {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        arg: Option<Vec<String>>,
+    },
+    /// Build the canonical blob for a new Validators List without signing it,
+    /// for signing offline on an air-gapped or HSM-backed machine.
+    PrepareSign {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        arg: Option<Vec<String>>,
+    },
+    /// Combine a blob produced by `PrepareSign` with an externally produced
+    /// signature into a final, verified Validators List.
+    CombineSign {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        arg: Option<Vec<String>>,
+    },
+    /// Verify a Validators List and exit with a stable, machine-readable code
+    /// per failure class, for cron jobs and monitoring pipelines.
+    Verify {
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        arg: Option<Vec<String>>,
+    },
+}
+
+/// A single reason a `Verify` run can fail, each with its own exit code so
+/// monitoring can tell failure classes apart without parsing text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationFailure {
+    BadSignature,
+    Expired,
+    NotYetEffective,
+    ExpiringSoon,
+}
+
+impl VerificationFailure {
+    pub fn exit_code(self) -> i32 {
+        match self {
+            VerificationFailure::BadSignature => 2,
+            VerificationFailure::Expired => 3,
+            VerificationFailure::NotYetEffective => 4,
+            VerificationFailure::ExpiringSoon => 5,
+        }
+    }
+
+    pub fn code_name(self) -> &'static str {
+        match self {
+            VerificationFailure::BadSignature => "bad_signature",
+            VerificationFailure::Expired => "expired",
+            VerificationFailure::NotYetEffective => "not_yet_effective",
+            VerificationFailure::ExpiringSoon => "expiring_soon",
+        }
+    }
+}
+
+/// Where the publisher's secp256k1 signing key is sourced from when running `Sign`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretProvider {
+    /// Raw hex-encoded private key stored in an environment variable.
+    Env,
+    /// Raw hex-encoded private key stored in a local file.
+    File,
+    /// Raw hex-encoded private key stored in AWS Secrets Manager.
+    AwsSecretsManager,
+    /// Key never leaves AWS KMS; signing is delegated to the KMS `Sign` API.
+    AwsKms,
+    /// Key is read from a HashiCorp Vault KV v2 secret.
+    Vault,
+    /// Key is derived on the spot from an XRPL family seed or BIP39 mnemonic
+    /// prompted for interactively on stdin.
+    Seed,
+}
+
+impl SecretProvider {
+    // Intentionally not `std::str::FromStr`: this returns a descriptive
+    // `anyhow::Error`, not an associated `Err` type.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "env" => Ok(SecretProvider::Env),
+            "file" => Ok(SecretProvider::File),
+            "aws-secrets-manager" | "aws_secrets_manager" => Ok(SecretProvider::AwsSecretsManager),
+            "aws-kms" | "aws_kms" => Ok(SecretProvider::AwsKms),
+            "vault" => Ok(SecretProvider::Vault),
+            "seed" => Ok(SecretProvider::Seed),
+            other => Err(anyhow!("Unknown secret provider: {other}")),
+        }
+    }
+
+    /// Whether this provider delegates the actual signing operation instead of
+    /// handing back raw key bytes for `sign_vl` to sign locally.
+    pub fn signs_remotely(self) -> bool {
+        matches!(self, SecretProvider::AwsKms)
+    }
+}
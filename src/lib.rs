@@ -0,0 +1,5 @@
+pub mod enums;
+pub mod structs;
+pub mod time;
+pub mod util;
+pub mod vl;
@@ -4,7 +4,7 @@ use clap::Parser;
 use color_eyre::owo_colors::OwoColorize;
 use xrpl_vl_tool::enums::{Commands, SecretProvider};
 use xrpl_vl_tool::time::{convert_to_human_time, convert_to_unix_time};
-use xrpl_vl_tool::vl::{load_vl, sign_vl, verify_vl};
+use xrpl_vl_tool::vl::{combine_sign_vl, evaluate_vl, load_vl, prepare_sign_vl, sign_vl, verify_vl};
 use xrpl_vl_tool::structs::Cli;
 use xrpl_vl_tool::util::{
     generate_vl_file, get_tick_or_cross, print_validators_summary
@@ -17,13 +17,33 @@ async fn main() -> Result<()> {
 
     match &cli.command {
         Commands::Load { arg } => {
-            let Some(url_or_file) = arg else {
+            let Some(params) = arg else {
+                return Err(anyhow!("No URL or file was passed"));
+            };
+            let mut params = params.clone();
+
+            let http_signature_public_key =
+                if let Some(pos) = params.iter().position(|p| p == "--http-sig-key") {
+                    params.remove(pos);
+                    if pos >= params.len() {
+                        return Err(anyhow!("--http-sig-key requires a hex-encoded public key"));
+                    }
+                    Some(params.remove(pos))
+                } else {
+                    None
+                };
+
+            let Some(url_or_file) = params.first() else {
                 return Err(anyhow!("No URL or file was passed"));
             };
 
-            let vl = load_vl(url_or_file).await?;
+            let vl = load_vl(url_or_file, http_signature_public_key.as_deref()).await?;
             let verified_vl = verify_vl(vl)?;
-  
+
+            if let Some(http_signature_verification) = verified_vl.http_signature_verification {
+                println!("Transport Signature: {}\n", get_tick_or_cross(http_signature_verification));
+            }
+
             if verified_vl.version == 1 {
                 // UNL Summary
                 let decoded_blob = verified_vl.blob.clone().unwrap();
@@ -50,9 +70,17 @@ async fn main() -> Result<()> {
             let Some(params) = arg else {
                 return Err(anyhow!("No URL or file was passed"));
             };
+            let mut params = params.clone();
+            let skip_seed_validation =
+                if let Some(pos) = params.iter().position(|p| p == "--skip-seed-validation") {
+                    params.remove(pos);
+                    true
+                } else {
+                    false
+                };
 
             if params.len() < 7 {
-                return Err(anyhow!("List of parameters: version, manifest, manifests, sequence, expiration_in_days, secret_provider, secret_id, effective_date (for v2), effective_time (for v2) and v2_vl_file(optional)."));
+                return Err(anyhow!("List of parameters: version, manifest, manifests, sequence, expiration_in_days, secret_provider, secret_id, effective_date (YYYY-MM-DD, for v2), effective_time (HH:MM:SS, for v2), v2_vl_file(optional) and --skip-seed-validation(optional, only for the seed provider)."));
             }
 
             let version = params[0].parse::<u8>()?;
@@ -64,9 +92,11 @@ async fn main() -> Result<()> {
             let secret_name = params[6].clone();
             let effective = if version == 2 {
                 if params.len() > 8 {
-                    Some(format!("{}{}", params[7].clone(), params[8].clone()))
+                    Some(format!("{} {}", params[7].clone(), params[8].clone()))
                 } else {
-                    return Err(anyhow!("Please specify a valid effective date and time"));
+                    return Err(anyhow!(
+                        "Please specify a valid effective date (YYYY-MM-DD) and time (HH:MM:SS)"
+                    ));
                 }
             } else {
                 None
@@ -87,12 +117,129 @@ async fn main() -> Result<()> {
                 secret_name,
                 effective,
                 v2_vl_file,
+                skip_seed_validation,
             ).await?;
 
             let vl_content = &serde_json::to_string(&vl)?;
             let file = generate_vl_file(vl_content, version).is_ok();
             println!("Validators List v{} file generated {}", version, get_tick_or_cross(file));
          }
+        Commands::PrepareSign { arg } => {
+            let Some(params) = arg else {
+                return Err(anyhow!("No parameters were passed"));
+            };
+
+            if params.len() < 4 {
+                return Err(anyhow!("List of parameters: version, manifests_file, sequence, expiration_in_days, effective_date and effective_time (for v2)."));
+            }
+
+            let version = params[0].parse::<u8>()?;
+            let manifests_file = params[1].clone();
+            let sequence = params[2].parse::<u32>()?;
+            let expiration_in_days = params[3].parse::<u16>()?;
+            let effective = if version == 2 {
+                if params.len() > 5 {
+                    Some(format!("{} {}", params[4].clone(), params[5].clone()))
+                } else {
+                    return Err(anyhow!(
+                        "Please specify a valid effective date (YYYY-MM-DD) and time (HH:MM:SS)"
+                    ));
+                }
+            } else {
+                None
+            };
+
+            let (blob_hex, manifest) =
+                prepare_sign_vl(sequence, expiration_in_days, effective, manifests_file)?;
+
+            println!("Blob to sign (hex): {}", blob_hex.green());
+            println!("Manifest to approve: {}", serde_json::to_string(&manifest)?);
+        }
+        Commands::CombineSign { arg } => {
+            let Some(params) = arg else {
+                return Err(anyhow!("No parameters were passed"));
+            };
+
+            if params.len() < 4 {
+                return Err(anyhow!("List of parameters: version, manifest, blob_hex, signature_hex and v2_vl_file(optional)."));
+            }
+
+            let version = params[0].parse::<u8>()?;
+            let manifest = params[1].clone();
+            let blob_hex = params[2].clone();
+            let signature_hex = params[3].clone();
+            let v2_vl_file = params.get(4).cloned();
+
+            let vl = combine_sign_vl(version, manifest, blob_hex, signature_hex, v2_vl_file)?;
+
+            let vl_content = &serde_json::to_string(&vl)?;
+            let file = generate_vl_file(vl_content, version).is_ok();
+            println!("Validators List v{} file generated {}", version, get_tick_or_cross(file));
+        }
+        Commands::Verify { arg } => {
+            let Some(params) = arg else {
+                return Err(anyhow!("No URL or file was passed"));
+            };
+            let mut params = params.clone();
+
+            let json_output = if let Some(pos) = params.iter().position(|p| p == "--json") {
+                params.remove(pos);
+                true
+            } else {
+                false
+            };
+
+            let warn_within_days = if let Some(pos) =
+                params.iter().position(|p| p == "--warn-within")
+            {
+                params.remove(pos);
+                if pos >= params.len() {
+                    return Err(anyhow!("--warn-within requires a number of days"));
+                }
+                Some(params.remove(pos).parse::<u16>()?)
+            } else {
+                None
+            };
+
+            let http_signature_public_key =
+                if let Some(pos) = params.iter().position(|p| p == "--http-sig-key") {
+                    params.remove(pos);
+                    if pos >= params.len() {
+                        return Err(anyhow!("--http-sig-key requires a hex-encoded public key"));
+                    }
+                    Some(params.remove(pos))
+                } else {
+                    None
+                };
+
+            let Some(url_or_file) = params.first() else {
+                return Err(anyhow!("No URL or file was passed"));
+            };
+
+            let vl = load_vl(url_or_file, http_signature_public_key.as_deref()).await?;
+            let verified_vl = verify_vl(vl)?;
+            let report = evaluate_vl(&verified_vl, warn_within_days);
+            let failure = report.worst_failure();
+
+            if json_output {
+                println!("{}", serde_json::to_string(&report)?);
+            } else {
+                if let Some(http_signature_verification) = report.http_signature_verification {
+                    println!("Transport Signature: {}", get_tick_or_cross(http_signature_verification));
+                }
+                println!(
+                    "Manifest Signature: {} | Blob Signature(s): {} | Expired: {} | Not yet effective: {} | Expiring soon: {} | Status: {}",
+                    get_tick_or_cross(report.manifest_verification),
+                    get_tick_or_cross(report.blob_statuses.iter().all(|status| status.verified)),
+                    get_tick_or_cross(!report.expired),
+                    get_tick_or_cross(!report.not_yet_effective),
+                    get_tick_or_cross(!report.expiring_soon),
+                    failure.map(|f| f.code_name()).unwrap_or("ok"),
+                );
+            }
+
+            std::process::exit(failure.map(|f| f.exit_code()).unwrap_or(0));
+        }
     }
 
     Ok(())
@@ -0,0 +1,61 @@
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::enums::Commands;
+
+#[derive(Debug, Parser)]
+#[command(name = "xrpl-unl-tool", version, about = "Create, sign and verify XRPL Validators Lists (UNLs)")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    pub validation_public_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedBlob {
+    pub sequence: u32,
+    pub expiration: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective: Option<u32>,
+    pub validators: Vec<ValidatorInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlobV2 {
+    pub manifest: String,
+    pub blob: String,
+    pub signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_blob: Option<DecodedBlob>,
+    #[serde(skip)]
+    pub blob_verification: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Vl {
+    pub version: u8,
+    pub manifest: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blobs_v2: Option<Vec<BlobV2>>,
+    /// Base64-encoded blob as received/produced on the wire, before decoding.
+    #[serde(rename = "blob", skip_serializing_if = "Option::is_none")]
+    pub raw_blob: Option<String>,
+    #[serde(skip)]
+    pub blob: Option<DecodedBlob>,
+    #[serde(skip)]
+    pub blob_verification: Option<bool>,
+    #[serde(skip)]
+    pub manifest_verification: Option<bool>,
+    /// Result of the opt-in Cavage-style HTTP Signature check on the response
+    /// `load_vl` fetched this VL from. `None` when that check was not requested.
+    #[serde(skip)]
+    pub http_signature_verification: Option<bool>,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Seconds between the Unix epoch and the Ripple epoch (2000-01-01T00:00:00Z).
+pub(crate) const RIPPLE_EPOCH_OFFSET: i64 = 946_684_800;
+
+pub fn convert_to_unix_time(ripple_time: u32) -> i64 {
+    ripple_time as i64 + RIPPLE_EPOCH_OFFSET
+}
+
+pub fn convert_to_human_time(unix_time: i64) -> String {
+    Utc.timestamp_opt(unix_time, 0)
+        .single()
+        .map(|dt: DateTime<Utc>| dt.to_rfc2822())
+        .unwrap_or_else(|| "invalid timestamp".to_string())
+}
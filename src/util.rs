@@ -0,0 +1,27 @@
+use anstream::println;
+use anyhow::Result;
+use color_eyre::owo_colors::OwoColorize;
+use std::fs;
+
+use crate::structs::ValidatorInfo;
+
+pub fn get_tick_or_cross(passed: bool) -> String {
+    if passed {
+        "✔".green().to_string()
+    } else {
+        "✘".red().to_string()
+    }
+}
+
+pub fn print_validators_summary(validators: Vec<ValidatorInfo>) -> Result<()> {
+    for validator in validators {
+        println!("  - {}", validator.validation_public_key);
+    }
+    Ok(())
+}
+
+pub fn generate_vl_file(content: &str, version: u8) -> Result<()> {
+    let file_name = format!("vl_v{version}.json");
+    fs::write(&file_name, content)?;
+    Ok(())
+}
@@ -0,0 +1,1227 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1, SecretKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use std::fs;
+
+use crate::enums::{SecretProvider, VerificationFailure};
+use crate::structs::{BlobV2, DecodedBlob, Vl};
+use crate::time::{convert_to_unix_time, RIPPLE_EPOCH_OFFSET};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RawBlobV2 {
+    manifest: String,
+    blob: String,
+    signature: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RawVl {
+    public_key: String,
+    manifest: String,
+    version: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blob: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blobs_v2: Option<Vec<RawBlobV2>>,
+}
+
+/// SHA512Half, the hash XRPL uses wherever a message needs to be reduced to 32 bytes before signing.
+fn sha512_half(data: &[u8]) -> [u8; 32] {
+    let digest = Sha512::digest(data);
+    let mut half = [0u8; 32];
+    half.copy_from_slice(&digest[..32]);
+    half
+}
+
+/// The manifest's embedded signing key and the signature it carries over the rest of the manifest.
+struct ManifestFields {
+    signing_public_key: PublicKey,
+    signature: Vec<u8>,
+    signed_data: Vec<u8>,
+}
+
+fn decode_manifest(manifest_b64: &str) -> Result<ManifestFields> {
+    let bytes = STANDARD.decode(manifest_b64)?;
+    let pubkey_len = *bytes.first().ok_or_else(|| anyhow!("Empty manifest"))? as usize;
+    let pubkey_start = 1;
+    let pubkey_end = pubkey_start + pubkey_len;
+    let signing_public_key = PublicKey::from_slice(
+        bytes
+            .get(pubkey_start..pubkey_end)
+            .ok_or_else(|| anyhow!("Truncated manifest"))?,
+    )?;
+
+    let sig_len = *bytes
+        .get(pubkey_end)
+        .ok_or_else(|| anyhow!("Truncated manifest"))? as usize;
+    let sig_start = pubkey_end + 1;
+    let sig_end = sig_start + sig_len;
+    let signature = bytes
+        .get(sig_start..sig_end)
+        .ok_or_else(|| anyhow!("Truncated manifest"))?
+        .to_vec();
+
+    Ok(ManifestFields {
+        signing_public_key,
+        signature,
+        signed_data: bytes[..pubkey_end].to_vec(),
+    })
+}
+
+fn verify_signature(public_key: &PublicKey, data: &[u8], signature: &[u8]) -> Result<bool> {
+    let secp = Secp256k1::verification_only();
+    let message = Message::from_digest(sha512_half(data));
+    let signature = Signature::from_der(signature)?;
+    Ok(secp.verify_ecdsa(&message, &signature, public_key).is_ok())
+}
+
+fn decode_blob(blob_b64: &str) -> Result<DecodedBlob> {
+    let bytes = STANDARD.decode(blob_b64)?;
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+/// Fetches and parses a Validators List from `url_or_file`. When
+/// `http_signature_public_key` (hex-encoded secp256k1 public key) is supplied
+/// and the source is a URL, the response must carry a valid Cavage-style HTTP
+/// `Signature` header from that key or the fetch is rejected outright, before
+/// the blob is even decoded.
+pub async fn load_vl(url_or_file: &str, http_signature_public_key: Option<&str>) -> Result<Vl> {
+    let (body, http_signature_verification) =
+        if url_or_file.starts_with("http://") || url_or_file.starts_with("https://") {
+            let response = reqwest::get(url_or_file).await?;
+            let verification = match http_signature_public_key {
+                Some(public_key_hex) => {
+                    let public_key = PublicKey::from_slice(&hex::decode(public_key_hex)?)?;
+                    if !verify_http_signature(response.headers(), &public_key)? {
+                        return Err(anyhow!(
+                            "HTTP Signature verification failed for {url_or_file}; rejecting response"
+                        ));
+                    }
+                    Some(true)
+                }
+                None => None,
+            };
+            (response.text().await?, verification)
+        } else {
+            (fs::read_to_string(url_or_file)?, None)
+        };
+
+    let raw: RawVl = serde_json::from_str(&body)?;
+
+    Ok(Vl {
+        version: raw.version,
+        manifest: raw.manifest,
+        signature: raw.signature,
+        raw_blob: raw.blob,
+        blobs_v2: raw.blobs_v2.map(|blobs| {
+            blobs
+                .into_iter()
+                .map(|b| BlobV2 {
+                    manifest: b.manifest,
+                    blob: b.blob,
+                    signature: b.signature,
+                    decoded_blob: None,
+                    blob_verification: None,
+                })
+                .collect()
+        }),
+        blob: None,
+        blob_verification: None,
+        manifest_verification: None,
+        http_signature_verification,
+    })
+}
+
+/// The parsed components of a Cavage-style `Signature` header.
+struct CavageSignature {
+    #[allow(dead_code)]
+    key_id: String,
+    algorithm: String,
+    headers: Vec<String>,
+    signature: Vec<u8>,
+    created: Option<i64>,
+    expires: Option<i64>,
+}
+
+fn parse_cavage_signature(header_value: &str) -> Result<CavageSignature> {
+    let mut key_id = None;
+    let mut algorithm = None;
+    let mut headers = Vec::new();
+    let mut signature = None;
+    let mut created = None;
+    let mut expires = None;
+
+    for part in header_value.split(',') {
+        let mut key_value = part.splitn(2, '=');
+        let key = key_value.next().unwrap_or("").trim();
+        let value = key_value
+            .next()
+            .unwrap_or("")
+            .trim()
+            .trim_matches('"');
+
+        match key {
+            "keyId" => key_id = Some(value.to_string()),
+            "algorithm" => algorithm = Some(value.to_string()),
+            "headers" => headers = value.split_whitespace().map(str::to_string).collect(),
+            "signature" => signature = Some(STANDARD.decode(value)?),
+            "created" => created = Some(value.parse::<i64>()?),
+            "expires" => expires = Some(value.parse::<i64>()?),
+            _ => {}
+        }
+    }
+
+    Ok(CavageSignature {
+        key_id: key_id.ok_or_else(|| anyhow!("Signature header is missing 'keyId'"))?,
+        algorithm: algorithm.ok_or_else(|| anyhow!("Signature header is missing 'algorithm'"))?,
+        headers: if headers.is_empty() {
+            vec!["(created)".to_string()]
+        } else {
+            headers
+        },
+        signature: signature.ok_or_else(|| anyhow!("Signature header is missing 'signature'"))?,
+        created,
+        expires,
+    })
+}
+
+/// Reconstructs the Cavage signing string: each listed header joined as
+/// `name: value` with newlines, with `(created)`/`(expires)` resolved from the
+/// signature parameters rather than real response headers.
+fn build_signing_string(
+    cavage: &CavageSignature,
+    response_headers: &reqwest::header::HeaderMap,
+) -> Result<String> {
+    let mut lines = Vec::with_capacity(cavage.headers.len());
+    for header_name in &cavage.headers {
+        let line = match header_name.as_str() {
+            "(created)" => format!(
+                "(created): {}",
+                cavage
+                    .created
+                    .ok_or_else(|| anyhow!("Signature lists '(created)' but has no 'created' param"))?
+            ),
+            "(expires)" => format!(
+                "(expires): {}",
+                cavage
+                    .expires
+                    .ok_or_else(|| anyhow!("Signature lists '(expires)' but has no 'expires' param"))?
+            ),
+            name => {
+                let value = response_headers
+                    .get(name)
+                    .ok_or_else(|| anyhow!("Response is missing header '{name}' required by its signature"))?
+                    .to_str()?;
+                format!("{name}: {value}")
+            }
+        };
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Allowed clock skew, in seconds, for a signature's `created` parameter.
+const HTTP_SIGNATURE_CLOCK_SKEW_SECONDS: i64 = 300;
+
+/// Verifies a Cavage signature over `signing_string` using whichever digest
+/// the signer's advertised `algorithm` calls for, against a secp256k1 key.
+fn verify_cavage_signature(
+    public_key: &PublicKey,
+    algorithm: &str,
+    signing_string: &[u8],
+    signature: &[u8],
+) -> Result<bool> {
+    let digest: [u8; 32] = match algorithm {
+        // Real-world Cavage signers; XRPL's own bespoke digest is not one of
+        // the registered Cavage algorithms, so it is not accepted here.
+        "ecdsa-sha256" | "hs2019" => Sha256::digest(signing_string).into(),
+        other => return Err(anyhow!("Unsupported HTTP Signature algorithm '{other}'")),
+    };
+    let secp = Secp256k1::verification_only();
+    let message = Message::from_digest(digest);
+    let signature = Signature::from_der(signature)?;
+    Ok(secp.verify_ecdsa(&message, &signature, public_key).is_ok())
+}
+
+fn verify_http_signature(
+    response_headers: &reqwest::header::HeaderMap,
+    expected_public_key: &PublicKey,
+) -> Result<bool> {
+    let header_value = response_headers
+        .get("Signature")
+        .ok_or_else(|| anyhow!("Response has no Signature header to verify"))?
+        .to_str()?;
+    let cavage = parse_cavage_signature(header_value)?;
+
+    let now = chrono::Utc::now().timestamp();
+    if cavage.expires.is_some_and(|expires| expires < now) {
+        return Ok(false);
+    }
+    if cavage
+        .created
+        .is_some_and(|created| created > now + HTTP_SIGNATURE_CLOCK_SKEW_SECONDS)
+    {
+        return Ok(false);
+    }
+
+    let signing_string = build_signing_string(&cavage, response_headers)?;
+    verify_cavage_signature(
+        expected_public_key,
+        &cavage.algorithm,
+        signing_string.as_bytes(),
+        &cavage.signature,
+    )
+}
+
+pub fn verify_vl(mut vl: Vl) -> Result<Vl> {
+    let manifest_fields = decode_manifest(&vl.manifest)?;
+    vl.manifest_verification = Some(verify_signature(
+        &manifest_fields.signing_public_key,
+        &manifest_fields.signed_data,
+        &manifest_fields.signature,
+    )?);
+
+    if vl.version == 1 {
+        let raw_blob = vl
+            .raw_blob
+            .clone()
+            .ok_or_else(|| anyhow!("Missing blob in v1 Validators List"))?;
+        let signature = hex::decode(
+            vl.signature
+                .clone()
+                .ok_or_else(|| anyhow!("Missing signature in v1 Validators List"))?,
+        )?;
+        let blob_bytes = STANDARD.decode(&raw_blob)?;
+        vl.blob_verification = Some(verify_signature(
+            &manifest_fields.signing_public_key,
+            &blob_bytes,
+            &signature,
+        )?);
+        vl.blob = Some(decode_blob(&raw_blob)?);
+    } else {
+        let blobs_v2 = vl
+            .blobs_v2
+            .take()
+            .ok_or_else(|| anyhow!("Missing blobs_v2 in v2 Validators List"))?;
+
+        let verified_blobs = blobs_v2
+            .into_iter()
+            .map(|mut blob_v2| -> Result<BlobV2> {
+                let blob_manifest = decode_manifest(&blob_v2.manifest)?;
+                let signature = hex::decode(&blob_v2.signature)?;
+                let blob_bytes = STANDARD.decode(&blob_v2.blob)?;
+                blob_v2.blob_verification = Some(verify_signature(
+                    &blob_manifest.signing_public_key,
+                    &blob_bytes,
+                    &signature,
+                )?);
+                blob_v2.decoded_blob = Some(decode_blob(&blob_v2.blob)?);
+                Ok(blob_v2)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        vl.blobs_v2 = Some(verified_blobs);
+    }
+
+    Ok(vl)
+}
+
+/// The expiry/effective/signature state of a single blob within a VL, as
+/// judged independently of its siblings.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlobStatus {
+    pub verified: bool,
+    pub expired: bool,
+    pub not_yet_effective: bool,
+    pub expiring_soon: bool,
+}
+
+/// The result of checking a verified VL against operational expectations
+/// (signatures, expiry, effective window), suitable for `--json` output.
+///
+/// A v2 list can legitimately carry several blobs at once (the one currently
+/// in force, plus one or more pre-published future ones), so `blob_statuses`
+/// keeps each blob's own state, while `expired`/`not_yet_effective`/
+/// `expiring_soon` below reflect only the blob actually in force right now
+/// (`current_blob_index`) -- not an OR across every blob in the list.
+#[derive(Debug, Serialize)]
+pub struct VerificationReport {
+    pub manifest_verification: bool,
+    pub blob_statuses: Vec<BlobStatus>,
+    /// Index into `blob_statuses` of the blob currently in force (the one
+    /// with the latest `effective` timestamp that has already started),
+    /// or `None` if no blob is in force yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_blob_index: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_signature_verification: Option<bool>,
+    pub expired: bool,
+    pub not_yet_effective: bool,
+    pub expiring_soon: bool,
+}
+
+impl VerificationReport {
+    /// The single worst failure class found, by severity, or `None` if the VL
+    /// is healthy. `Verify` exits with this failure's code. Only the blob
+    /// currently in force (and the manifest/HTTP signature, which apply to
+    /// the whole list) can fail a healthy multi-blob v2 list.
+    pub fn worst_failure(&self) -> Option<VerificationFailure> {
+        // No currently-in-force blob means there is nothing to validate a
+        // signature against yet; `not_yet_effective` below covers that case.
+        let current_blob_verified = self
+            .current_blob_index
+            .and_then(|index| self.blob_statuses.get(index))
+            .map(|status| status.verified)
+            .unwrap_or(true);
+
+        if !self.manifest_verification
+            || !current_blob_verified
+            || self.http_signature_verification == Some(false)
+        {
+            Some(VerificationFailure::BadSignature)
+        } else if self.expired {
+            Some(VerificationFailure::Expired)
+        } else if self.not_yet_effective {
+            Some(VerificationFailure::NotYetEffective)
+        } else if self.expiring_soon {
+            Some(VerificationFailure::ExpiringSoon)
+        } else {
+            None
+        }
+    }
+}
+
+/// Evaluates an already-`verify_vl`'d list against expiry/effective windows.
+/// `warn_within_days`, when set, flags a currently-in-force blob expiring
+/// within that many days even if it has not expired yet.
+pub fn evaluate_vl(vl: &Vl, warn_within_days: Option<u16>) -> VerificationReport {
+    let now = chrono::Utc::now().timestamp();
+    let warn_within_seconds = warn_within_days.map(|days| days as i64 * 86_400);
+
+    struct Entry {
+        verified: bool,
+        expiration_unix: i64,
+        effective_unix: Option<i64>,
+    }
+
+    let to_entry = |decoded_blob: &DecodedBlob, verified: Option<bool>| Entry {
+        verified: verified.unwrap_or(false),
+        expiration_unix: convert_to_unix_time(decoded_blob.expiration),
+        effective_unix: decoded_blob.effective.map(convert_to_unix_time),
+    };
+
+    let entries: Vec<Entry> = if vl.version == 1 {
+        vl.blob
+            .iter()
+            .map(|decoded_blob| to_entry(decoded_blob, vl.blob_verification))
+            .collect()
+    } else {
+        vl.blobs_v2
+            .iter()
+            .flatten()
+            .filter_map(|blob_v2| {
+                blob_v2
+                    .decoded_blob
+                    .as_ref()
+                    .map(|decoded_blob| to_entry(decoded_blob, blob_v2.blob_verification))
+            })
+            .collect()
+    };
+
+    let blob_statuses: Vec<BlobStatus> = entries
+        .iter()
+        .map(|entry| {
+            let expired = entry.expiration_unix <= now;
+            let not_yet_effective = entry.effective_unix.is_some_and(|eff| eff > now);
+            let expiring_soon = !expired
+                && !not_yet_effective
+                && warn_within_seconds.is_some_and(|within| entry.expiration_unix - now <= within);
+            BlobStatus {
+                verified: entry.verified,
+                expired,
+                not_yet_effective,
+                expiring_soon,
+            }
+        })
+        .collect();
+
+    // The blob actually in force right now is the one, among those whose
+    // effective window has already opened, with the latest `effective`
+    // timestamp.
+    let current_blob_index = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.effective_unix.is_none_or(|eff| eff <= now))
+        .max_by_key(|(_, entry)| entry.effective_unix.unwrap_or(i64::MIN))
+        .map(|(index, _)| index);
+
+    let (expired, not_yet_effective, expiring_soon) = match current_blob_index
+        .and_then(|index| blob_statuses.get(index))
+    {
+        Some(current) => (current.expired, false, current.expiring_soon),
+        // No blob's effective window has opened yet, but there is at least
+        // one blob scheduled: the list as a whole is not yet effective.
+        None => (false, !entries.is_empty(), false),
+    };
+
+    VerificationReport {
+        manifest_verification: vl.manifest_verification.unwrap_or(false),
+        blob_statuses,
+        current_blob_index,
+        http_signature_verification: vl.http_signature_verification,
+        expired,
+        not_yet_effective,
+        expiring_soon,
+    }
+}
+
+/// Reads the hex-encoded secp256k1 private key material for `secret_name` out of `provider`.
+/// Not valid for providers that sign remotely (see [`SecretProvider::signs_remotely`]).
+///
+/// `expected_public_key` and `skip_seed_validation` are only consulted for
+/// [`SecretProvider::Seed`], which derives a key instead of looking one up.
+async fn resolve_local_secret_key(
+    provider: SecretProvider,
+    secret_name: &str,
+    expected_public_key: &PublicKey,
+    skip_seed_validation: bool,
+) -> Result<SecretKey> {
+    if provider == SecretProvider::Seed {
+        return resolve_seed_secret_key(expected_public_key, skip_seed_validation);
+    }
+
+    let raw = match provider {
+        SecretProvider::Env => std::env::var(secret_name)
+            .map_err(|_| anyhow!("Secret '{secret_name}' was not found in the environment"))?,
+        SecretProvider::File => fs::read_to_string(secret_name)?.trim().to_string(),
+        SecretProvider::AwsSecretsManager => fetch_aws_secrets_manager_secret(secret_name).await?,
+        SecretProvider::Vault => fetch_vault_secret(secret_name).await?,
+        SecretProvider::Seed => unreachable!("handled above"),
+        SecretProvider::AwsKms => {
+            return Err(anyhow!("AwsKms signs remotely and has no local key material"))
+        }
+    };
+    let bytes = hex::decode(raw.trim())?;
+    Ok(SecretKey::from_slice(&bytes)?)
+}
+
+/// The alphabet XRPL uses for base58check-encoded addresses, seeds and keys
+/// (not the Bitcoin alphabet it is commonly confused with).
+const RIPPLE_BASE58_ALPHABET: &[u8; 58] =
+    b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// Version byte for a secp256k1 family seed.
+const FAMILY_SEED_VERSION: u8 = 0x21;
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    use hmac::{Hmac, Mac};
+    let mut mac =
+        Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts a key of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Decodes an XRPL family seed (`s...`) into its 16 raw entropy bytes.
+fn decode_family_seed(seed: &str) -> Result<Vec<u8>> {
+    let alphabet = bs58::Alphabet::new(RIPPLE_BASE58_ALPHABET)
+        .map_err(|e| anyhow!("Invalid Ripple base58 alphabet: {e}"))?;
+    let decoded = bs58::decode(seed)
+        .with_alphabet(&alphabet)
+        .with_check(None)
+        .into_vec()
+        .map_err(|e| anyhow!("Invalid family seed: {e}"))?;
+
+    if decoded.first() != Some(&FAMILY_SEED_VERSION) {
+        return Err(anyhow!(
+            "Only secp256k1 family seeds are supported (ed25519 seeds are not)"
+        ));
+    }
+    Ok(decoded[1..].to_vec())
+}
+
+/// XRPL's `deriveScalar`: hashes `bytes` (plus an optional discriminator) with
+/// an incrementing counter until the result is a valid secp256k1 scalar.
+fn derive_scalar(bytes: &[u8], discriminator: Option<u32>) -> Result<SecretKey> {
+    for counter in 0u32..u32::MAX {
+        let mut input = bytes.to_vec();
+        if let Some(discriminator) = discriminator {
+            input.extend_from_slice(&discriminator.to_be_bytes());
+        }
+        input.extend_from_slice(&counter.to_be_bytes());
+        if let Ok(key) = SecretKey::from_slice(&sha512_half(&input)) {
+            return Ok(key);
+        }
+    }
+    Err(anyhow!("Could not derive a valid secp256k1 key"))
+}
+
+/// Derives an XRPL account secp256k1 key pair from family seed entropy,
+/// matching `ripple-keypairs`'/rippled's `derivePrivateKey`: a root key pair
+/// derived straight from the seed, then the account key is
+/// `(deriveScalar(rootPublicKey, accountIndex) + rootPrivateKey) mod n`.
+fn derive_secp256k1_from_seed_bytes(seed_bytes: &[u8]) -> Result<SecretKey> {
+    let secp = Secp256k1::new();
+    let root_secret_key = derive_scalar(seed_bytes, None)?;
+    let root_public_key = PublicKey::from_secret_key(&secp, &root_secret_key);
+    let intermediate = derive_scalar(&root_public_key.serialize(), Some(0))?;
+    let tweak = secp256k1::Scalar::from(intermediate);
+    Ok(root_secret_key.add_tweak(&tweak)?)
+}
+
+/// An extended private key: a secp256k1 scalar plus its BIP32 chain code.
+struct ExtendedSecretKey {
+    secret_key: SecretKey,
+    chain_code: [u8; 32],
+}
+
+fn bip32_master_key(seed: &[u8]) -> Result<ExtendedSecretKey> {
+    let i = hmac_sha512(b"Bitcoin seed", seed);
+    let secret_key = SecretKey::from_slice(&i[..32])?;
+    let mut chain_code = [0u8; 32];
+    chain_code.copy_from_slice(&i[32..]);
+    Ok(ExtendedSecretKey {
+        secret_key,
+        chain_code,
+    })
+}
+
+fn bip32_derive_child(
+    parent: &ExtendedSecretKey,
+    index: u32,
+    hardened: bool,
+) -> Result<ExtendedSecretKey> {
+    let secp = Secp256k1::new();
+    let index = if hardened { index | 0x8000_0000 } else { index };
+
+    let mut data = Vec::with_capacity(37);
+    if hardened {
+        data.push(0);
+        data.extend_from_slice(&parent.secret_key.secret_bytes());
+    } else {
+        let public_key = PublicKey::from_secret_key(&secp, &parent.secret_key);
+        data.extend_from_slice(&public_key.serialize());
+    }
+    data.extend_from_slice(&index.to_be_bytes());
+
+    let i = hmac_sha512(&parent.chain_code, &data);
+    let tweak = secp256k1::Scalar::from_be_bytes(i[..32].try_into().unwrap())
+        .map_err(|_| anyhow!("Invalid BIP32 child tweak"))?;
+    let secret_key = parent.secret_key.add_tweak(&tweak)?;
+    let mut chain_code = [0u8; 32];
+    chain_code.copy_from_slice(&i[32..]);
+    Ok(ExtendedSecretKey {
+        secret_key,
+        chain_code,
+    })
+}
+
+/// Derives a secp256k1 signing key from BIP39 mnemonic entropy using standard
+/// BIP32 along XRPL's registered BIP44 path (`m/44'/144'/0'/0/0`, coin type
+/// 144 for XRP). There is no ed25519 support: XRPL's ed25519 keys are not
+/// BIP32-derived, so a mnemonic can only ever produce a secp256k1 key here.
+fn derive_secp256k1_from_mnemonic(mnemonic: &bip39::Mnemonic) -> Result<SecretKey> {
+    let seed = mnemonic.to_seed("");
+    let master = bip32_master_key(&seed)?;
+    let purpose = bip32_derive_child(&master, 44, true)?;
+    let coin_type = bip32_derive_child(&purpose, 144, true)?;
+    let account = bip32_derive_child(&coin_type, 0, true)?;
+    let change = bip32_derive_child(&account, 0, false)?;
+    let address_index = bip32_derive_child(&change, 0, false)?;
+    Ok(address_index.secret_key)
+}
+
+/// Prompts on stdin (no echo) for an XRPL family seed (`s...`, secp256k1 only)
+/// or a BIP39 mnemonic, derives the corresponding secp256k1 signing key via
+/// XRPL's own derivation (family seed) or BIP32/BIP44 `m/44'/144'/0'/0/0`
+/// (mnemonic), then checks it against the publisher's manifest unless
+/// `skip_validation` is set.
+fn resolve_seed_secret_key(
+    expected_public_key: &PublicKey,
+    skip_validation: bool,
+) -> Result<SecretKey> {
+    let input = rpassword::prompt_password("Enter XRPL family seed or BIP39 mnemonic: ")?;
+    let input = input.trim();
+
+    // A family seed is a single base58 token; a mnemonic is several words.
+    let secret_key = if input.starts_with('s') && !input.contains(char::is_whitespace) {
+        derive_secp256k1_from_seed_bytes(&decode_family_seed(input)?)?
+    } else {
+        derive_secp256k1_from_mnemonic(&bip39::Mnemonic::parse(input)?)?
+    };
+
+    if !skip_validation {
+        let secp = Secp256k1::signing_only();
+        let derived_public_key = PublicKey::from_secret_key(&secp, &secret_key);
+        if &derived_public_key != expected_public_key {
+            return Err(anyhow!(
+                "Derived public key does not match the signing key in the supplied manifest; pass --skip-seed-validation to override"
+            ));
+        }
+    }
+
+    Ok(secret_key)
+}
+
+/// Obtains a Vault client token. Logs in via AppRole (`VAULT_ROLE_ID`/`VAULT_SECRET_ID`) if both are
+/// present, otherwise falls back to a plain `VAULT_TOKEN`. AppRole is the
+/// pattern automated signing pipelines use, since it avoids handing out a
+/// long-lived token.
+async fn vault_client_token() -> Result<String> {
+    if let (Ok(role_id), Ok(secret_id)) = (
+        std::env::var("VAULT_ROLE_ID"),
+        std::env::var("VAULT_SECRET_ID"),
+    ) {
+        let vault_addr = std::env::var("VAULT_ADDR")
+            .map_err(|_| anyhow!("VAULT_ADDR must be set to read the Vault backend"))?;
+        let mount = std::env::var("VAULT_APPROLE_MOUNT").unwrap_or_else(|_| "approle".to_string());
+        let settings = vaultrs::client::VaultClientSettingsBuilder::default()
+            .address(vault_addr)
+            .build()?;
+        let client = vaultrs::client::VaultClient::new(settings)?;
+        let auth_info = vaultrs::auth::approle::login(&client, &mount, &role_id, &secret_id).await?;
+        return Ok(auth_info.client_token);
+    }
+
+    std::env::var("VAULT_TOKEN")
+        .map_err(|_| anyhow!("Set VAULT_ROLE_ID/VAULT_SECRET_ID or VAULT_TOKEN to read the Vault backend"))
+}
+
+/// Reads a `private_key` field out of a HashiCorp Vault KV v2 secret at `<mount>/<path>`.
+async fn fetch_vault_secret(secret_id: &str) -> Result<String> {
+    let vault_addr = std::env::var("VAULT_ADDR")
+        .map_err(|_| anyhow!("VAULT_ADDR must be set to read the Vault backend"))?;
+    let vault_token = vault_client_token().await?;
+
+    let settings = vaultrs::client::VaultClientSettingsBuilder::default()
+        .address(vault_addr)
+        .token(vault_token)
+        .build()?;
+    let client = vaultrs::client::VaultClient::new(settings)?;
+
+    let (mount, path) = secret_id
+        .split_once('/')
+        .ok_or_else(|| anyhow!("Vault secret id must be '<mount>/<path>'"))?;
+    let secret: std::collections::HashMap<String, String> =
+        vaultrs::kv2::read(&client, mount, path).await?;
+    secret
+        .get("private_key")
+        .cloned()
+        .ok_or_else(|| anyhow!("Vault secret at '{secret_id}' has no 'private_key' field"))
+}
+
+async fn fetch_aws_secrets_manager_secret(secret_name: &str) -> Result<String> {
+    let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+    let client = aws_sdk_secretsmanager::Client::new(&config);
+    let output = client.get_secret_value().secret_id(secret_name).send().await?;
+    output
+        .secret_string()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Secret '{secret_name}' has no string value"))
+}
+
+fn sign_with_local_key(secret_key: &SecretKey, data: &[u8]) -> Signature {
+    let secp = Secp256k1::signing_only();
+    let message = Message::from_digest(sha512_half(data));
+    secp.sign_ecdsa(&message, secret_key)
+}
+
+async fn sign_with_aws_kms(key_id: &str, data: &[u8]) -> Result<Signature> {
+    let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+    let client = aws_sdk_kms::Client::new(&config);
+    let digest = sha512_half(data);
+    let output = client
+        .sign()
+        .key_id(key_id)
+        .message_type(aws_sdk_kms::types::MessageType::Digest)
+        .signing_algorithm(aws_sdk_kms::types::SigningAlgorithmSpec::EcdsaSha256)
+        .message(aws_sdk_kms::primitives::Blob::new(digest.to_vec()))
+        .send()
+        .await?;
+    let signature_bytes = output
+        .signature()
+        .ok_or_else(|| anyhow!("KMS returned no signature for key '{key_id}'"))?
+        .as_ref();
+    Ok(Signature::from_der(signature_bytes)?)
+}
+
+/// Parses an operator-supplied effective date/time (`YYYY-MM-DD HH:MM:SS`, UTC)
+/// into a Ripple timestamp.
+fn parse_effective_timestamp(effective: &str) -> Result<u32> {
+    let naive = chrono::NaiveDateTime::parse_from_str(effective, "%Y-%m-%d %H:%M:%S")
+        .map_err(|_| {
+            anyhow!("Invalid effective date/time '{effective}'; expected 'YYYY-MM-DD HH:MM:SS'")
+        })?;
+    let ripple_time = naive.and_utc().timestamp() - RIPPLE_EPOCH_OFFSET;
+    u32::try_from(ripple_time)
+        .map_err(|_| anyhow!("Effective date/time '{effective}' is out of range"))
+}
+
+/// Builds the blob bytes that get signed for a new VL entry. Shared by `sign_vl`
+/// and `prepare_sign_vl` so both serialize a blob the same way; the expiration is
+/// counted from the current time, so calls made at different times yield
+/// different bytes.
+fn build_decoded_blob(
+    sequence: u32,
+    expiration_in_days: u16,
+    effective: Option<String>,
+    manifests_file: &str,
+) -> Result<(DecodedBlob, Vec<u8>)> {
+    let expiration = ((chrono::Utc::now()
+        + chrono::Duration::days(expiration_in_days as i64))
+    .timestamp()
+        - RIPPLE_EPOCH_OFFSET) as u32;
+
+    let validators: Vec<crate::structs::ValidatorInfo> =
+        serde_json::from_str(&fs::read_to_string(manifests_file)?)?;
+
+    let decoded_blob = DecodedBlob {
+        sequence,
+        expiration,
+        effective: effective.as_deref().map(parse_effective_timestamp).transpose()?,
+        validators,
+    };
+    let blob_bytes = serde_json::to_vec(&decoded_blob)?;
+    Ok((decoded_blob, blob_bytes))
+}
+
+/// Assembles a final `Vl` from an already-produced signature, merging into an
+/// existing v2 list (`v2_vl_file`) when one is supplied. Used by both `sign_vl`
+/// (which produces the signature itself) and `combine_sign_vl` (which receives
+/// one produced offline).
+fn assemble_signed_vl(
+    version: u8,
+    manifest: String,
+    decoded_blob: DecodedBlob,
+    blob_bytes: &[u8],
+    signature_hex: String,
+    v2_vl_file: Option<String>,
+) -> Result<Vl> {
+    let blob_b64 = STANDARD.encode(blob_bytes);
+
+    if version == 1 {
+        Ok(Vl {
+            version,
+            manifest,
+            signature: Some(signature_hex),
+            raw_blob: Some(blob_b64),
+            blob: Some(decoded_blob),
+            blobs_v2: None,
+            blob_verification: None,
+            manifest_verification: None,
+            http_signature_verification: None,
+        })
+    } else {
+        let mut blobs_v2 = if let Some(v2_vl_file) = v2_vl_file {
+            let existing: RawVl = serde_json::from_str(&fs::read_to_string(&v2_vl_file)?)?;
+            existing
+                .blobs_v2
+                .unwrap_or_default()
+                .into_iter()
+                .map(|b| BlobV2 {
+                    manifest: b.manifest,
+                    blob: b.blob,
+                    signature: b.signature,
+                    decoded_blob: None,
+                    blob_verification: None,
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        blobs_v2.push(BlobV2 {
+            manifest: manifest.clone(),
+            blob: blob_b64,
+            signature: signature_hex,
+            decoded_blob: Some(decoded_blob),
+            blob_verification: None,
+        });
+
+        Ok(Vl {
+            version,
+            manifest,
+            signature: None,
+            raw_blob: None,
+            blob: None,
+            blobs_v2: Some(blobs_v2),
+            blob_verification: None,
+            manifest_verification: None,
+            http_signature_verification: None,
+        })
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn sign_vl(
+    version: u8,
+    manifest: String,
+    manifests_file: String,
+    sequence: u32,
+    expiration_in_days: u16,
+    secret_provider: SecretProvider,
+    secret_name: String,
+    effective: Option<String>,
+    v2_vl_file: Option<String>,
+    skip_seed_validation: bool,
+) -> Result<Vl> {
+    let (decoded_blob, blob_bytes) =
+        build_decoded_blob(sequence, expiration_in_days, effective, &manifests_file)?;
+
+    let signature = if secret_provider.signs_remotely() {
+        match secret_provider {
+            SecretProvider::AwsKms => sign_with_aws_kms(&secret_name, &blob_bytes).await?,
+            _ => unreachable!("signs_remotely() only returns true for AwsKms"),
+        }
+    } else {
+        let expected_public_key = decode_manifest(&manifest)?.signing_public_key;
+        let secret_key = resolve_local_secret_key(
+            secret_provider,
+            &secret_name,
+            &expected_public_key,
+            skip_seed_validation,
+        )
+        .await?;
+        sign_with_local_key(&secret_key, &blob_bytes)
+    };
+
+    let signature_hex = hex::encode(signature.serialize_der());
+
+    assemble_signed_vl(
+        version,
+        manifest,
+        decoded_blob,
+        &blob_bytes,
+        signature_hex,
+        v2_vl_file,
+    )
+}
+
+/// A small, human-readable description of what an offline signer is being asked
+/// to approve: no key material, just the fields that end up in the blob.
+#[derive(Debug, Serialize)]
+pub struct PrepareSignManifest {
+    pub sequence: u32,
+    pub expiration: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective: Option<u32>,
+}
+
+/// Builds the canonical blob bytes for a new VL entry without touching any key
+/// material, returning them hex-encoded alongside a JSON manifest describing
+/// what is about to be signed. The hex payload is exactly what `combine_sign_vl`
+/// expects the externally produced signature to cover.
+pub fn prepare_sign_vl(
+    sequence: u32,
+    expiration_in_days: u16,
+    effective: Option<String>,
+    manifests_file: String,
+) -> Result<(String, PrepareSignManifest)> {
+    let (decoded_blob, blob_bytes) =
+        build_decoded_blob(sequence, expiration_in_days, effective, &manifests_file)?;
+
+    let manifest = PrepareSignManifest {
+        sequence: decoded_blob.sequence,
+        expiration: decoded_blob.expiration,
+        effective: decoded_blob.effective,
+    };
+
+    Ok((hex::encode(blob_bytes), manifest))
+}
+
+/// Combines the hex blob emitted by `prepare_sign_vl` with a signature produced
+/// offline, assembles the final VL via the same path `sign_vl` uses, and
+/// verifies it before it is ever written to disk.
+pub fn combine_sign_vl(
+    version: u8,
+    manifest: String,
+    blob_hex: String,
+    signature_hex: String,
+    v2_vl_file: Option<String>,
+) -> Result<Vl> {
+    let blob_bytes = hex::decode(blob_hex)?;
+    let decoded_blob: DecodedBlob = serde_json::from_slice(&blob_bytes)?;
+
+    let vl = assemble_signed_vl(
+        version,
+        manifest,
+        decoded_blob,
+        &blob_bytes,
+        signature_hex,
+        v2_vl_file,
+    )?;
+
+    let verified = verify_vl(vl)?;
+    let signed_ok = if version == 1 {
+        verified.blob_verification == Some(true)
+    } else {
+        verified
+            .blobs_v2
+            .as_ref()
+            .and_then(|blobs| blobs.last())
+            .and_then(|b| b.blob_verification)
+            == Some(true)
+    };
+
+    if !signed_ok {
+        return Err(anyhow!(
+            "The supplied signature does not validate against the prepared blob"
+        ));
+    }
+
+    Ok(verified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::ValidatorInfo;
+
+    fn ripple_time_from_now(offset_seconds: i64) -> u32 {
+        let unix_time = chrono::Utc::now().timestamp() + offset_seconds;
+        (unix_time - RIPPLE_EPOCH_OFFSET) as u32
+    }
+
+    fn decoded_blob(expiration_offset: i64, effective_offset: Option<i64>) -> DecodedBlob {
+        DecodedBlob {
+            sequence: 1,
+            expiration: ripple_time_from_now(expiration_offset),
+            effective: effective_offset.map(ripple_time_from_now),
+            validators: vec![ValidatorInfo {
+                validation_public_key: "nValidator".to_string(),
+                manifest: None,
+            }],
+        }
+    }
+
+    fn blob_v2(decoded: DecodedBlob, verified: bool) -> BlobV2 {
+        BlobV2 {
+            manifest: String::new(),
+            blob: String::new(),
+            signature: String::new(),
+            decoded_blob: Some(decoded),
+            blob_verification: Some(verified),
+        }
+    }
+
+    fn v2_vl(blobs: Vec<BlobV2>) -> Vl {
+        Vl {
+            version: 2,
+            manifest: String::new(),
+            signature: None,
+            blobs_v2: Some(blobs),
+            raw_blob: None,
+            blob: None,
+            blob_verification: None,
+            manifest_verification: Some(true),
+            http_signature_verification: None,
+        }
+    }
+
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn evaluate_vl_ignores_a_healthy_future_blob() {
+        let current = blob_v2(decoded_blob(30 * DAY, Some(-DAY)), true);
+        let future = blob_v2(decoded_blob(60 * DAY, Some(10 * DAY)), true);
+        let vl = v2_vl(vec![current, future]);
+
+        let report = evaluate_vl(&vl, None);
+
+        assert_eq!(report.current_blob_index, Some(0));
+        assert!(!report.expired);
+        assert!(!report.not_yet_effective);
+        assert!(report.worst_failure().is_none());
+    }
+
+    #[test]
+    fn evaluate_vl_flags_expiry_of_the_current_blob_only() {
+        let expired = blob_v2(decoded_blob(-DAY, Some(-30 * DAY)), true);
+        let future = blob_v2(decoded_blob(60 * DAY, Some(10 * DAY)), true);
+        let vl = v2_vl(vec![expired, future]);
+
+        let report = evaluate_vl(&vl, None);
+
+        assert_eq!(report.current_blob_index, Some(0));
+        assert!(report.expired);
+        assert!(!report.not_yet_effective);
+        assert_eq!(report.worst_failure(), Some(VerificationFailure::Expired));
+    }
+
+    #[test]
+    fn evaluate_vl_flags_not_yet_effective_when_nothing_is_in_force_yet() {
+        let future = blob_v2(decoded_blob(60 * DAY, Some(10 * DAY)), true);
+        let vl = v2_vl(vec![future]);
+
+        let report = evaluate_vl(&vl, None);
+
+        assert_eq!(report.current_blob_index, None);
+        assert!(report.not_yet_effective);
+        assert_eq!(
+            report.worst_failure(),
+            Some(VerificationFailure::NotYetEffective)
+        );
+    }
+
+    #[test]
+    fn evaluate_vl_flags_a_bad_signature_on_the_current_blob_only() {
+        let current = blob_v2(decoded_blob(30 * DAY, Some(-DAY)), false);
+        let future = blob_v2(decoded_blob(60 * DAY, Some(10 * DAY)), true);
+        let vl = v2_vl(vec![current, future]);
+
+        let report = evaluate_vl(&vl, None);
+
+        assert_eq!(report.current_blob_index, Some(0));
+        assert_eq!(
+            report.worst_failure(),
+            Some(VerificationFailure::BadSignature)
+        );
+    }
+
+    #[test]
+    fn evaluate_vl_warns_when_the_current_blob_expires_soon() {
+        let current = blob_v2(decoded_blob(2 * DAY, None), true);
+        let vl = v2_vl(vec![current]);
+
+        let report = evaluate_vl(&vl, Some(7));
+
+        assert!(!report.expired);
+        assert!(report.expiring_soon);
+        assert_eq!(
+            report.worst_failure(),
+            Some(VerificationFailure::ExpiringSoon)
+        );
+    }
+
+    #[test]
+    fn parse_cavage_signature_reads_all_params() {
+        let header = concat!(
+            r#"keyId="publisher-1",algorithm="ecdsa-sha256","#,
+            r#"headers="(created) (expires) host",signature="aGVsbG8=","#,
+            r#"created="1000",expires="2000""#,
+        );
+
+        let cavage = parse_cavage_signature(header).unwrap();
+
+        assert_eq!(cavage.key_id, "publisher-1");
+        assert_eq!(cavage.algorithm, "ecdsa-sha256");
+        assert_eq!(cavage.headers, vec!["(created)", "(expires)", "host"]);
+        assert_eq!(cavage.signature, b"hello");
+        assert_eq!(cavage.created, Some(1000));
+        assert_eq!(cavage.expires, Some(2000));
+    }
+
+    #[test]
+    fn parse_cavage_signature_defaults_headers_to_created() {
+        let header = r#"keyId="publisher-1",algorithm="ecdsa-sha256",signature="aGVsbG8=""#;
+
+        let cavage = parse_cavage_signature(header).unwrap();
+
+        assert_eq!(cavage.headers, vec!["(created)"]);
+    }
+
+    #[test]
+    fn parse_cavage_signature_requires_signature() {
+        let header = r#"keyId="publisher-1",algorithm="ecdsa-sha256""#;
+
+        assert!(parse_cavage_signature(header).is_err());
+    }
+
+    #[test]
+    fn build_signing_string_resolves_pseudo_and_real_headers() {
+        let cavage = CavageSignature {
+            key_id: "publisher-1".to_string(),
+            algorithm: "ecdsa-sha256".to_string(),
+            headers: vec!["(created)".to_string(), "host".to_string()],
+            signature: vec![],
+            created: Some(1000),
+            expires: None,
+        };
+        let mut response_headers = reqwest::header::HeaderMap::new();
+        response_headers.insert("host", "vl.example.com".parse().unwrap());
+
+        let signing_string = build_signing_string(&cavage, &response_headers).unwrap();
+
+        assert_eq!(signing_string, "(created): 1000\nhost: vl.example.com");
+    }
+
+    #[test]
+    fn build_signing_string_errors_on_missing_response_header() {
+        let cavage = CavageSignature {
+            key_id: "publisher-1".to_string(),
+            algorithm: "ecdsa-sha256".to_string(),
+            headers: vec!["digest".to_string()],
+            signature: vec![],
+            created: None,
+            expires: None,
+        };
+
+        assert!(build_signing_string(&cavage, &reqwest::header::HeaderMap::new()).is_err());
+    }
+
+    #[test]
+    fn verify_cavage_signature_round_trips_ecdsa_sha256() {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+        let signing_string = b"(created): 1000\nhost: vl.example.com";
+        let digest = Sha256::digest(signing_string);
+        let message = Message::from_digest(digest.into());
+        let signature = secp.sign_ecdsa(&message, &secret_key);
+
+        let verified = verify_cavage_signature(
+            &public_key,
+            "ecdsa-sha256",
+            signing_string,
+            &signature.serialize_der(),
+        )
+        .unwrap();
+
+        assert!(verified);
+    }
+
+    #[test]
+    fn verify_cavage_signature_rejects_unsupported_algorithm() {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+
+        let result = verify_cavage_signature(&public_key, "rsa-sha256", b"anything", &[]);
+
+        assert!(result.is_err());
+    }
+
+    // Known-answer vector: rippled's well-known "genesis" test account, whose
+    // seed is derived from the passphrase "masterpassphrase" and whose
+    // resulting keys/address appear throughout rippled's own test suite and
+    // the XRPL docs' key-generation examples.
+    #[test]
+    fn derive_secp256k1_from_seed_bytes_matches_known_xrpl_account() {
+        let seed_bytes = decode_family_seed("snoPBrXtMeMyMHUVTgbuqAfg1SUTb").unwrap();
+        let secret_key = derive_secp256k1_from_seed_bytes(&seed_bytes).unwrap();
+        let secp = Secp256k1::new();
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key);
+
+        assert_eq!(
+            hex::encode(public_key.serialize()),
+            "0330e7fc9d56bb25d6893ba3f317ae5bcf33b3291bd63db32654a313222f7fd020"
+        );
+    }
+
+    #[test]
+    fn derive_secp256k1_from_mnemonic_is_deterministic() {
+        let mnemonic = bip39::Mnemonic::parse_in(
+            bip39::Language::English,
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+
+        let first = derive_secp256k1_from_mnemonic(&mnemonic).unwrap();
+        let second = derive_secp256k1_from_mnemonic(&mnemonic).unwrap();
+
+        assert_eq!(first.secret_bytes(), second.secret_bytes());
+    }
+}